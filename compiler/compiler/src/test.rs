@@ -16,9 +16,10 @@
 
 use std::{
     cell::RefCell,
-    fmt, fs,
+    env, fmt, fs,
     path::{Path, PathBuf},
     rc::Rc,
+    str::FromStr,
 };
 
 use crate::Compiler;
@@ -28,16 +29,129 @@ use leo_errors::{
     LeoError, LeoWarning,
 };
 use leo_passes::SymbolTable;
-use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then};
+use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then, Symbol};
 use leo_test_framework::{
     runner::{Namespace, ParseType, Runner},
     Test,
 };
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
 use serde_yaml::Value;
 
-fn new_compiler(handler: &Handler, main_file_path: PathBuf) -> Compiler<'_> {
-    let output_dir = PathBuf::from("/tmp/output/");
+use snarkvm::prelude::{Process, Program, Testnet3, Value as SvmValue};
+
+/// The only network this harness can actually compile/execute against right now. `TestNetwork`
+/// below validates and labels the `network:` test config key; it doesn't yet select among
+/// multiple concrete networks, since this snarkVM version only has a `Testnet3` to pick.
+type CurrentNetwork = Testnet3;
+
+/// Separates multiple Leo program sources within a single test file, so one fixture can
+/// declare several programs (e.g. a record-defining program and a program that imports it)
+/// and exercise cross-program import resolution. Programs are compiled in the order
+/// they're written, which must also be dependency order.
+const PROGRAM_DELIMITER: &str = "// --- Next Program --- //";
+
+/// The network named by a test's `network:` config key. Only `testnet3` actually runs today
+/// (see `CurrentNetwork`); `mainnet` parses as a recognized name but is rejected at config time
+/// until this harness has a second `Network` to dispatch to.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+enum TestNetwork {
+    Testnet3,
+}
+
+impl TestNetwork {
+    /// The name used in test config (`network: testnet3`) and in this network's working directory.
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Testnet3 => "testnet3",
+        }
+    }
+}
+
+impl FromStr for TestNetwork {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "testnet3" => Ok(Self::Testnet3),
+            "mainnet" => Err("`mainnet` is a recognized network name, but this harness has no `Network` impl for it yet".to_string()),
+            other => Err(format!("unknown network `{other}`, expected `testnet3`")),
+        }
+    }
+}
+
+/// Parse the `network:` key of the test config, defaulting to `testnet3` when absent.
+///
+/// Only a single network name is accepted: this harness has nothing to dispatch a second
+/// network to yet, so a list (the shape a real multi-network run would need) is rejected
+/// instead of silently only ever running the first/only variant that exists.
+fn collect_test_network(test: &Test) -> Result<TestNetwork, String> {
+    let Some(network) = test.config.get("network") else {
+        return Ok(TestNetwork::Testnet3);
+    };
+
+    network
+        .as_str()
+        .ok_or_else(|| "`network` must be a single network name; this harness can't run more than one yet".to_string())?
+        .parse()
+}
+
+/// Pulls the `program <name>.aleo` identifier out of a program's source, so multi-program
+/// tests can name and key each segment without a separate config entry.
+fn extract_program_name(program_string: &str) -> String {
+    program_string
+        .split_whitespace()
+        .skip_while(|&word| word != "program")
+        .nth(1)
+        .and_then(|name| name.strip_suffix(".aleo"))
+        .expect("could not find a `program <name>.aleo` declaration")
+        .to_string()
+}
+
+/// Disassembles generated AVM `bytecode` back into its human-readable Aleo instruction
+/// listing, so test expectations can be diffed/reviewed as readable instructions instead of
+/// an opaque hash of the bytecode string.
+fn disassemble_from_str(bytecode: &str) -> Result<String, String> {
+    Program::<CurrentNetwork>::from_str(bytecode).map(|program| program.to_string()).map_err(|err| err.to_string())
+}
+
+/// Writes `bytecode` out as an Aleo program package under a fresh temporary directory
+/// named after `program_name`, and loads it into a fresh snarkVM `Process`.
+///
+/// This mirrors what `leo build` would set up on disk, but scoped to a throwaway
+/// directory so tests don't need a real package layout on the filesystem.
+fn setup_build_directory(test_id: &str, program_name: &str, bytecode: &str) -> Result<Process<CurrentNetwork>, String> {
+    // Initialize a fresh temporary directory for the program package, unique per test file so
+    // two `Execute` fixtures naming the same program don't race on the same directory.
+    let directory = std::env::temp_dir().join(format!("leo-execute-test-{test_id}-{program_name}"));
+    if directory.exists() {
+        fs::remove_dir_all(&directory).expect("failed to clean up stale execute test directory");
+    }
+    fs::create_dir_all(&directory).expect("failed to create execute test directory");
+
+    // Write out the generated bytecode as the program's `main.aleo` file, for debugging.
+    let program_path = directory.join(format!("{program_name}.aleo"));
+    fs::write(&program_path, bytecode).expect("failed to write bytecode to build directory");
+
+    // Parse the bytecode into a snarkVM `Program` and load it into a fresh `Process`.
+    let program = Program::<CurrentNetwork>::from_str(bytecode).map_err(|err| err.to_string())?;
+
+    let mut process = Process::<CurrentNetwork>::load().map_err(|err| err.to_string())?;
+    process.add_program(&program).map_err(|err| err.to_string())?;
+
+    Ok(process)
+}
+
+/// A working directory for `network`, unique to this test file.
+fn test_output_dir(test: &Test, network: TestNetwork) -> PathBuf {
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    let test_id = hash_content(&test.path.display().to_string());
+
+    Path::new(manifest_dir).join("tmp").join(format!("{}-{test_id}", network.name()))
+}
+
+fn new_compiler(handler: &Handler, main_file_path: PathBuf, output_dir: PathBuf) -> Compiler<'_> {
     fs::create_dir_all(output_dir.clone()).unwrap();
 
     Compiler::new(handler, main_file_path, output_dir)
@@ -47,8 +161,9 @@ fn parse_program<'a>(
     handler: &'a Handler,
     program_string: &str,
     cwd: Option<PathBuf>,
+    output_dir: PathBuf,
 ) -> Result<Compiler<'a>, LeoError> {
-    let mut compiler = new_compiler(handler, cwd.clone().unwrap_or_else(|| "compiler-test".into()));
+    let mut compiler = new_compiler(handler, cwd.clone().unwrap_or_else(|| "compiler-test".into()), output_dir);
     let name = cwd.map_or_else(|| FileName::Custom("compiler-test".into()), FileName::Real);
     compiler.parse_program_from_string(program_string, name)?;
 
@@ -64,9 +179,85 @@ fn hash_content(content: &str) -> String {
     format!("{:x}", hash)
 }
 
-fn hash_file(path: &str) -> String {
-    let file = fs::read_to_string(&Path::new(path)).unwrap();
-    hash_content(&file)
+/// Strips a single volatile key (e.g. `span`, `id`) from every object in `value`, recursing
+/// through arrays and nested objects.
+fn remove_key_from_json(value: &mut JsonValue, key: &str) {
+    match value {
+        JsonValue::Object(map) => {
+            map.remove(key);
+            for nested in map.values_mut() {
+                remove_key_from_json(nested, key);
+            }
+        }
+        JsonValue::Array(items) => {
+            for item in items {
+                remove_key_from_json(item, key);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Strips `span`/`id` keys and this machine's temp-directory path from a parsed AST value,
+/// so it's stable to snapshot.
+fn normalize_json_value(mut value: JsonValue, temp_dir: &Path) -> JsonValue {
+    remove_key_from_json(&mut value, "span");
+    remove_key_from_json(&mut value, "id");
+
+    let temp_dir = temp_dir.display().to_string();
+    normalize_json_strings(&mut value, &temp_dir);
+    value
+}
+
+/// Replaces any occurrence of `temp_dir` inside string leaves of `value` with a stable
+/// placeholder, so absolute paths baked into the AST don't leak into expectation files.
+fn normalize_json_strings(value: &mut JsonValue, temp_dir: &str) {
+    match value {
+        JsonValue::String(s) if s.contains(temp_dir) => {
+            *s = s.replace(temp_dir, "<temp_dir>");
+        }
+        JsonValue::Object(map) => {
+            for nested in map.values_mut() {
+                normalize_json_strings(nested, temp_dir);
+            }
+        }
+        JsonValue::Array(items) => {
+            for item in items {
+                normalize_json_strings(item, temp_dir);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Reads the JSON file at `path` and returns it normalized (see `normalize_json_value`).
+fn normalized_json_file(path: &Path, temp_dir: &Path) -> JsonValue {
+    let file = fs::read_to_string(path).unwrap();
+    let value: JsonValue = serde_json::from_str(&file).unwrap();
+
+    normalize_json_value(value, temp_dir)
+}
+
+/// Like `normalize_json_value`, but for plain `Display` output such as the symbol table.
+fn normalize_text(text: &str, temp_dir: &Path) -> String {
+    text.replace(&temp_dir.display().to_string(), "<temp_dir>")
+}
+
+/// Whether expectation files should be regenerated in place rather than checked against,
+/// controlled by setting the `REWRITE_EXPECTATIONS` environment variable to any value.
+fn is_rewriting_expectations() -> bool {
+    env::var("REWRITE_EXPECTATIONS").is_ok()
+}
+
+/// When `REWRITE_EXPECTATIONS` is set, overwrite the test's `.out` expectation file with `output`.
+fn rewrite_expectation_if_requested(test: &Test, output: &Value) {
+    if !is_rewriting_expectations() {
+        return;
+    }
+
+    let expectation_path = test.path.with_extension("out");
+    let rendered = serde_yaml::to_string(output).expect("failed to serialize expectation");
+    fs::write(expectation_path, rendered).expect("failed to rewrite expectation file");
 }
 
 struct CompileNamespace;
@@ -86,14 +277,16 @@ impl Namespace for CompileNamespace {
 
 #[derive(Deserialize, PartialEq, Serialize)]
 struct OutputItem {
-    pub initial_input_ast: String,
+    pub initial_input_ast: JsonValue,
 }
 
+/// The recorded outcome of compiling one program from a (possibly multi-program) test file.
 #[derive(Deserialize, PartialEq, Serialize)]
 struct CompileOutput {
     pub output: Vec<OutputItem>,
-    pub initial_ast: String,
+    pub initial_ast: JsonValue,
     pub symbol_table: String,
+    pub bytecode: String,
 }
 
 /// Get the path of the `input_file` given in `input` into `list`.
@@ -112,11 +305,26 @@ fn get_input_file_paths(list: &mut Vec<PathBuf>, test: &Test, input: &Value) {
     }
 }
 
-/// Collect and return all inputs, if possible.
-fn collect_all_inputs(test: &Test) -> Result<Vec<PathBuf>, String> {
+/// Collect and return the inputs for `program_name`, if possible.
+///
+/// In a single-program test, `input_file` is a plain path or list of paths. In a multi-program
+/// test it must instead be a mapping keyed by program name, so one program's inputs aren't
+/// also parsed and recorded against every other program in the file.
+fn collect_all_inputs(test: &Test, program_name: &str, is_multi_program: bool) -> Result<Vec<PathBuf>, String> {
     let mut list = vec![];
 
-    if let Some(input) = test.config.get("input_file") {
+    let Some(input) = test.config.get("input_file") else {
+        return Ok(list);
+    };
+
+    if is_multi_program {
+        let mapping = input
+            .as_mapping()
+            .ok_or_else(|| "multi-program tests must key `input_file` by program name".to_string())?;
+        if let Some(program_input) = mapping.get(&Value::String(program_name.to_string())) {
+            get_input_file_paths(&mut list, test, program_input);
+        }
+    } else {
         get_input_file_paths(&mut list, test, input);
     }
 
@@ -179,41 +387,296 @@ fn run_test(test: Test, handler: &Handler, err_buf: &BufferEmitter) -> Result<Va
         cwd.join(&val.as_str().unwrap())
     });
 
-    let mut parsed = handler.extend_if_error(parse_program(handler, &test.content, cwd))?;
+    let network = buffer_if_err(err_buf, collect_test_network(&test))?;
+    let programs = compile_all_programs(&test, handler, err_buf, cwd, network)?;
+
+    let final_output = serde_yaml::to_value(&programs).expect("serialization failed");
+    rewrite_expectation_if_requested(&test, &final_output);
+    Ok(final_output)
+}
+
+/// Compiles every program in a (possibly multi-program) test file against `network`,
+/// returning each program's `CompileOutput` keyed by program name.
+fn compile_all_programs(
+    test: &Test,
+    handler: &Handler,
+    err_buf: &BufferEmitter,
+    cwd: Option<PathBuf>,
+    network: TestNetwork,
+) -> Result<IndexMap<String, CompileOutput>, ()> {
+    let output_dir = test_output_dir(test, network);
+
+    // Split the test content on `PROGRAM_DELIMITER` so a single fixture can declare several
+    // programs. Each program is written out under `imports/` as it's compiled, so later
+    // programs in the file can `import` earlier ones the same way they'd import any other
+    // on-disk dependency.
+    let programs: Vec<&str> = test.content.split(PROGRAM_DELIMITER).map(str::trim).collect();
+    let is_multi_program = programs.len() > 1;
+
+    if is_multi_program && cwd.is_some() {
+        return buffer_if_err(
+            err_buf,
+            Err("`cwd` and multi-program test files can't be combined: each compiled program is written \
+                 into `<cwd>/imports/`, which would overwrite whatever `cwd` already points at"
+                .to_string()),
+        );
+    }
 
-    // (name, content)
-    let inputs = buffer_if_err(err_buf, collect_all_inputs(&test))?;
+    // Deliberately not a descendant of `output_dir`: that directory is wiped after every
+    // program in the loop below, which would take `imports/` down with it.
+    let imports_dir = {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tmp")
+            .join(format!("imports-{}", hash_content(&test.path.display().to_string())));
+        if dir.exists() {
+            fs::remove_dir_all(&dir).expect("failed to clean up stale imports directory");
+        }
+        dir
+    };
+    if is_multi_program {
+        fs::create_dir_all(imports_dir.join("imports")).expect("failed to create imports directory");
+    }
 
-    let mut output_items = Vec::with_capacity(inputs.len());
+    let mut outputs = IndexMap::new();
 
-    if inputs.is_empty() {
-        output_items.push(OutputItem {
-            initial_input_ast: "no input".to_string(),
-        });
-    } else {
-        for input in inputs {
-            let mut parsed = parsed.clone();
-            handler.extend_if_error(parsed.parse_input(input))?;
-            let initial_input_ast = hash_file("/tmp/output/inital_input_ast.json");
+    for program_string in programs {
+        let name = extract_program_name(program_string);
 
-            output_items.push(OutputItem { initial_input_ast });
+        let program_cwd = if is_multi_program { Some(imports_dir.clone()) } else { cwd.clone() };
+        let mut parsed =
+            handler.extend_if_error(parse_program(handler, program_string, program_cwd, output_dir.clone()))?;
+
+        // (name, content)
+        let inputs = buffer_if_err(err_buf, collect_all_inputs(test, &name, is_multi_program))?;
+
+        let mut output_items = Vec::with_capacity(inputs.len());
+
+        if inputs.is_empty() {
+            output_items.push(OutputItem {
+                initial_input_ast: JsonValue::String("no input".to_string()),
+            });
+        } else {
+            for input in inputs {
+                let mut parsed = parsed.clone();
+                handler.extend_if_error(parsed.parse_input(input))?;
+                let initial_input_ast = normalized_json_file(&output_dir.join("inital_input_ast.json"), &output_dir);
+
+                output_items.push(OutputItem { initial_input_ast });
+            }
         }
+
+        let symbol_table = handler.extend_if_error(compile_and_process(&mut parsed))?;
+
+        let initial_ast = normalized_json_file(&output_dir.join("initial_ast.json"), &output_dir);
+        let raw_bytecode = handler.extend_if_error(parsed.generate_bytecode())?;
+        let bytecode = buffer_if_err(err_buf, disassemble_from_str(&raw_bytecode))?;
+
+        if fs::read_dir(&output_dir).is_ok() {
+            fs::remove_dir_all(&output_dir).expect("Error failed to clean up output dir.");
+        }
+
+        outputs.insert(
+            name.clone(),
+            CompileOutput {
+                output: output_items,
+                initial_ast,
+                symbol_table: normalize_text(&symbol_table.to_string(), &output_dir),
+                bytecode,
+            },
+        );
+
+        // Make this program importable by any programs that follow it in the file.
+        if is_multi_program {
+            fs::write(imports_dir.join("imports").join(format!("{name}.leo")), program_string)
+                .expect("failed to write program for import by subsequent programs");
+        }
+    }
+
+    // Clean up the scratch imports directory we created, same as `output_dir` above.
+    if imports_dir.exists() {
+        fs::remove_dir_all(&imports_dir).expect("failed to clean up imports directory");
+    }
+
+    Ok(outputs)
+}
+
+/// A single `(function, inputs)` case to execute, as read from a test's `cases:` config.
+/// Each `input` entry is a literal snarkVM value, or `record:<Name>` to auto-synthesize one.
+#[derive(Deserialize)]
+struct ExecuteCase {
+    function: String,
+    input: Vec<String>,
+}
+
+/// Placeholder address used to fill a synthesized record's `owner` field when the test
+/// config doesn't specify one via `owner: aleo1...`.
+const DEFAULT_TEST_OWNER_ADDRESS: &str = "aleo1qnrdenr8ff0tqw6tvh2tj6ezagkgzfqz5g6jh8ltcd537xaxzv9sg0mmr6";
+
+/// The address to fill into a synthesized record's `owner` field, from the test's
+/// `owner: aleo1...` config key, defaulting to `DEFAULT_TEST_OWNER_ADDRESS`.
+fn collect_owner_address(test: &Test) -> String {
+    test.config.get("owner").and_then(Value::as_str).unwrap_or(DEFAULT_TEST_OWNER_ADDRESS).to_string()
+}
+
+/// The zero/default literal for a record or struct member's type, used to fill data fields
+/// that a test case doesn't care about the value of.
+fn default_literal_for_type(type_name: &str) -> Result<&'static str, String> {
+    Ok(match type_name {
+        "address" => DEFAULT_TEST_OWNER_ADDRESS,
+        "bool" => "false",
+        "field" => "0field",
+        "group" => "0group",
+        "scalar" => "0scalar",
+        "i8" => "0i8",
+        "i16" => "0i16",
+        "i32" => "0i32",
+        "i64" => "0i64",
+        "i128" => "0i128",
+        "u8" => "0u8",
+        "u16" => "0u16",
+        "u32" => "0u32",
+        "u64" => "0u64",
+        "u128" => "0u128",
+        other => return Err(format!("don't know how to synthesize a default value for type `{other}`")),
+    })
+}
+
+/// Generates a literal for the record named `record_name`, filling `owner`/`_nonce` and
+/// defaulting every other data field via `default_literal_for_type`.
+fn synthesize_record_input(symbol_table: &SymbolTable, record_name: &str, owner_address: &str) -> Result<String, String> {
+    let record = symbol_table
+        .structs
+        .get(&Symbol::intern(record_name))
+        .filter(|record| record.is_record)
+        .ok_or_else(|| format!("no record named `{record_name}` is defined in this program"))?;
+
+    let mut fields = vec![format!("owner: {owner_address}.private"), "_nonce: 0group.public".to_string()];
+
+    for member in &record.members {
+        let field_name = member.identifier.name;
+        if field_name == Symbol::intern("owner") || field_name == Symbol::intern("_nonce") {
+            continue;
+        }
+
+        let default = default_literal_for_type(&member.type_.to_string())?;
+        fields.push(format!("{field_name}: {default}.private"));
+    }
+
+    Ok(format!("{{ {} }}", fields.join(", ")))
+}
+
+/// Resolves one `ExecuteCase::input` entry into the literal snarkVM value text to parse,
+/// synthesizing a record value for `record:<Name>` entries (see `synthesize_record_input`).
+fn resolve_execute_input(literal: &str, symbol_table: &SymbolTable, owner_address: &str) -> Result<String, String> {
+    match literal.strip_prefix("record:") {
+        Some(record_name) => synthesize_record_input(symbol_table, record_name.trim(), owner_address),
+        None => Ok(literal.to_string()),
+    }
+}
+
+/// The recorded outcome of running one `ExecuteCase` through a loaded `Process`.
+#[derive(Deserialize, PartialEq, Serialize)]
+struct ExecuteItem {
+    pub function: String,
+    pub output: Vec<String>,
+}
+
+#[derive(Deserialize, PartialEq, Serialize)]
+struct ExecuteOutput {
+    pub initial_ast: JsonValue,
+    pub symbol_table: String,
+    pub bytecode: String,
+    pub execute: Vec<ExecuteItem>,
+}
+
+struct ExecuteNamespace;
+
+impl Namespace for ExecuteNamespace {
+    fn parse_type(&self) -> ParseType {
+        ParseType::Whole
     }
 
+    fn run_test(&self, test: Test) -> Result<Value, String> {
+        let buf = BufferEmitter(Rc::default(), Rc::default());
+        let handler = Handler::new(Box::new(buf.clone()));
+
+        create_session_if_not_set_then(|_| run_execute_test(test, &handler, &buf).map_err(|()| buf.0.take().to_string()))
+    }
+}
+
+/// Parse the `cases:` key of the test config into a list of `ExecuteCase`s to run.
+fn collect_execute_cases(test: &Test) -> Result<Vec<ExecuteCase>, String> {
+    let cases = test.config.get("cases").ok_or_else(|| "missing `cases` in test config".to_string())?;
+
+    serde_yaml::from_value(cases.clone()).map_err(|err| err.to_string())
+}
+
+fn run_execute_test(test: Test, handler: &Handler, err_buf: &BufferEmitter) -> Result<Value, ()> {
+    let network = buffer_if_err(err_buf, collect_test_network(&test))?;
+    let output = execute_program(&test, handler, err_buf, network)?;
+
+    let final_output = serde_yaml::to_value(&output).expect("serialization failed");
+    rewrite_expectation_if_requested(&test, &final_output);
+    Ok(final_output)
+}
+
+/// Compiles and executes the test's program against `network`, recording the results of
+/// every `(function, inputs)` case listed in the test's `cases:` config.
+fn execute_program(
+    test: &Test,
+    handler: &Handler,
+    err_buf: &BufferEmitter,
+    network: TestNetwork,
+) -> Result<ExecuteOutput, ()> {
+    let output_dir = test_output_dir(test, network);
+
+    let cwd = test.config.get("cwd").map(|val| {
+        let mut cwd = test.path.clone();
+        cwd.pop();
+        cwd.join(&val.as_str().unwrap())
+    });
+
+    let mut parsed = handler.extend_if_error(parse_program(handler, &test.content, cwd, output_dir.clone()))?;
+
     let symbol_table = handler.extend_if_error(compile_and_process(&mut parsed))?;
+    let initial_ast = normalized_json_file(&output_dir.join("initial_ast.json"), &output_dir);
+    let raw_bytecode = handler.extend_if_error(parsed.generate_bytecode())?;
+    let bytecode = buffer_if_err(err_buf, disassemble_from_str(&raw_bytecode))?;
 
-    let initial_ast = hash_file("/tmp/output/initial_ast.json");
+    if fs::read_dir(&output_dir).is_ok() {
+        fs::remove_dir_all(&output_dir).expect("Error failed to clean up output dir.");
+    }
 
-    if fs::read_dir("/tmp/output").is_ok() {
-        fs::remove_dir_all(Path::new("/tmp/output")).expect("Error failed to clean up output dir.");
+    let cases = buffer_if_err(err_buf, collect_execute_cases(test))?;
+    let owner_address = collect_owner_address(test);
+    let program_name = parsed.program_name().to_string();
+    let test_id = hash_content(&test.path.display().to_string());
+    let mut process = buffer_if_err(err_buf, setup_build_directory(&test_id, &program_name, &raw_bytecode))?;
+
+    let mut execute = Vec::with_capacity(cases.len());
+    for case in cases {
+        let resolved_inputs: Vec<String> = buffer_if_err(
+            err_buf,
+            case.input.iter().map(|literal| resolve_execute_input(literal, &symbol_table, &owner_address)).collect(),
+        )?;
+
+        let inputs: Vec<SvmValue<CurrentNetwork>> = buffer_if_err(
+            err_buf,
+            resolved_inputs.iter().map(|i| i.parse().map_err(|e: anyhow::Error| e.to_string())).collect(),
+        )?;
+
+        let output = buffer_if_err(
+            err_buf,
+            process.execute(&program_name, &case.function, inputs.into_iter()).map_err(|err| err.to_string()),
+        )?;
+
+        execute.push(ExecuteItem {
+            function: case.function,
+            output: output.iter().map(|value| value.to_string()).collect(),
+        });
     }
 
-    let final_output = CompileOutput {
-        output: output_items,
-        initial_ast,
-        symbol_table: hash_content(&symbol_table.to_string()),
-    };
-    Ok(serde_yaml::to_value(&final_output).expect("serialization failed"))
+    Ok(ExecuteOutput { initial_ast, symbol_table: normalize_text(&symbol_table.to_string(), &output_dir), bytecode, execute })
 }
 
 struct TestRunner;
@@ -222,6 +685,7 @@ impl Runner for TestRunner {
     fn resolve_namespace(&self, name: &str) -> Option<Box<dyn Namespace>> {
         Some(match name {
             "Compile" => Box::new(CompileNamespace),
+            "Execute" => Box::new(ExecuteNamespace),
             _ => return None,
         })
     }